@@ -41,6 +41,9 @@ const (
 	GrowthManifestFile       = "growth-manifest.json"
 	ProductDocsFile          = "product-docs.md"
 	ImplementationPromptFile = "implementation-prompt.md"
+	GrowthReportFile         = "growth-report.md"
+	GrowthHistoryFile        = "growth-history.jsonl"
+	GrowthTrendFile          = "growth-trend.md"
 )
 
 // Skene ecosystem package metadata
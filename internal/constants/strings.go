@@ -92,6 +92,18 @@ var NextStepActions = []NextStepDef{
 		Description: "Validate the growth manifest against the schema",
 		Command:     "uvx skene-growth validate",
 	},
+	{
+		ID:          "report",
+		Name:        "Build Growth Report",
+		Description: "Bundle the manifest, template, plan, docs and prompt into one growth-report.md",
+		Command:     "",
+	},
+	{
+		ID:          "trend",
+		Name:        "Show Growth Trend",
+		Description: "Summarize how manifest counts changed across runs in growth-history.jsonl",
+		Command:     "",
+	},
 	{
 		ID:          "rerun",
 		Name:        "Re-run Analysis",
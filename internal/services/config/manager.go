@@ -3,22 +3,26 @@ package config
 import (
 	"encoding/json"
 	"fmt"
+	"net/url"
 	"os"
 	"path/filepath"
+	"strings"
 
 	"skene/internal/constants"
 )
 
 // Config represents the skene-growth configuration
 type Config struct {
-	Provider     string `json:"provider"`
-	Model        string `json:"model"`
-	APIKey       string `json:"api_key"`
-	OutputDir    string `json:"output_dir"`
-	Verbose      bool   `json:"verbose"`
-	ProjectDir   string `json:"project_dir"`
-	BaseURL      string `json:"base_url,omitempty"`
-	UseGrowth bool `json:"use_growth"`
+	Provider      string `json:"provider"`
+	Model         string `json:"model"`
+	APIKey        string `json:"api_key"`
+	OutputDir     string `json:"output_dir"`
+	Verbose       bool   `json:"verbose"`
+	ProjectDir    string `json:"project_dir"`
+	BaseURL       string `json:"base_url,omitempty"`
+	UseGrowth     bool   `json:"use_growth"`
+	NotifyWebhook string `json:"notify_webhook,omitempty"`
+	TrackHistory  bool   `json:"track_history,omitempty"`
 }
 
 // Manager handles configuration file operations
@@ -178,8 +182,61 @@ func (m *Manager) SetProjectDir(dir string) {
 }
 
 // SetBaseURL sets the base URL for generic providers
-func (m *Manager) SetBaseURL(url string) {
-	m.Config.BaseURL = url
+func (m *Manager) SetBaseURL(baseURL string) {
+	m.Config.BaseURL = NormalizeBaseURL(baseURL)
+}
+
+// NormalizeBaseURL cleans up a user-entered base URL so it can be passed to
+// skene-growth as the API root: surrounding whitespace, duplicate and trailing
+// slashes are removed, a pasted /chat/completions endpoint is stripped since
+// the OpenAI-compatible client appends it itself, and a host-only URL gets the
+// /v1 root used by OpenAI-compatible servers. URLs without a scheme default to
+// https, except loopback hosts which use http.
+func NormalizeBaseURL(raw string) string {
+	trimmed := strings.TrimSpace(raw)
+	if trimmed == "" {
+		return ""
+	}
+	if !strings.Contains(trimmed, "://") {
+		if isLoopbackHost(trimmed) {
+			trimmed = "http://" + trimmed
+		} else {
+			trimmed = "https://" + trimmed
+		}
+	}
+
+	u, err := url.Parse(trimmed)
+	if err != nil || u.Host == "" {
+		return strings.TrimRight(strings.TrimSpace(raw), "/")
+	}
+
+	path := u.Path
+	for strings.Contains(path, "//") {
+		path = strings.ReplaceAll(path, "//", "/")
+	}
+	path = strings.TrimRight(path, "/")
+	hostOnly := path == ""
+	path = strings.TrimSuffix(path, "/chat/completions")
+	if hostOnly {
+		path = "/v1"
+	}
+
+	u.Path = path
+	u.RawPath = ""
+	return u.String()
+}
+
+// isLoopbackHost reports whether a scheme-less URL points at this machine
+func isLoopbackHost(schemeless string) bool {
+	u, err := url.Parse("http://" + schemeless)
+	if err != nil {
+		return false
+	}
+	switch u.Hostname() {
+	case "localhost", "127.0.0.1", "::1":
+		return true
+	}
+	return false
 }
 
 // GetMaskedAPIKey returns masked API key for display
@@ -324,6 +381,16 @@ func GetProviderByID(id string) *Provider {
 	return nil
 }
 
+// DefaultModelForProvider returns the model used when none is configured.
+// Generic providers have no meaningful default, so an empty string is returned.
+func DefaultModelForProvider(id string) string {
+	p := GetProviderByID(id)
+	if p == nil || p.IsGeneric || len(p.Models) == 0 {
+		return ""
+	}
+	return p.Models[0].ID
+}
+
 // IsLocalProvider returns true if the provider runs locally
 func IsLocalProvider(id string) bool {
 	p := GetProviderByID(id)
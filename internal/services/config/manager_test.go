@@ -0,0 +1,35 @@
+package config
+
+import "testing"
+
+func TestNormalizeBaseURL(t *testing.T) {
+	tests := []struct {
+		name string
+		in   string
+		want string
+	}{
+		{name: "empty", in: "", want: ""},
+		{name: "host only", in: "http://localhost:11434", want: "http://localhost:11434/v1"},
+		{name: "host with trailing slash", in: "http://localhost:11434/", want: "http://localhost:11434/v1"},
+		{name: "api root", in: "http://localhost:1234/v1", want: "http://localhost:1234/v1"},
+		{name: "trailing slash", in: "https://api.example.com/v1/", want: "https://api.example.com/v1"},
+		{name: "surrounding whitespace", in: "  https://api.example.com/v1  ", want: "https://api.example.com/v1"},
+		{name: "full completions path", in: "https://api.example.com/v1/chat/completions", want: "https://api.example.com/v1"},
+		{name: "full path with trailing slash", in: "https://api.example.com/v1/chat/completions/", want: "https://api.example.com/v1"},
+		{name: "duplicate slashes", in: "https://api.example.com//openai//v1/", want: "https://api.example.com/openai/v1"},
+		{name: "no scheme", in: "localhost:1234/v1/chat/completions/", want: "http://localhost:1234/v1"},
+		{name: "no scheme host only", in: "localhost:11434", want: "http://localhost:11434/v1"},
+		{name: "no scheme remote host", in: "api.groq.com/openai/v1", want: "https://api.groq.com/openai/v1"},
+		{name: "no scheme loopback ip", in: "127.0.0.1:8080", want: "http://127.0.0.1:8080/v1"},
+		{name: "no scheme ipv6 loopback", in: "[::1]:8080/v1", want: "http://[::1]:8080/v1"},
+		{name: "root-level completions endpoint", in: "https://proxy.example.com/chat/completions", want: "https://proxy.example.com"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := NormalizeBaseURL(tt.in); got != tt.want {
+				t.Errorf("NormalizeBaseURL(%q) = %q, want %q", tt.in, got, tt.want)
+			}
+		})
+	}
+}
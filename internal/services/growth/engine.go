@@ -11,6 +11,7 @@ import (
 	"time"
 
 	"skene/internal/constants"
+	"skene/internal/services/config"
 	"skene/internal/services/uvresolver"
 )
 
@@ -76,7 +77,14 @@ type EngineConfig struct {
 	BaseURL    string
 	ProjectDir string
 	OutputDir  string
-	UseGrowth bool
+	UseGrowth  bool
+
+	// NotifyWebhook, when set, receives a CompletionNotice after each command
+	NotifyWebhook string
+
+	// TrackHistory appends the manifest counts to growth-history.jsonl after
+	// each successful analysis
+	TrackHistory bool
 }
 
 // Engine spawns uvx commands to run Skene libraries in the selected repository
@@ -84,6 +92,10 @@ type Engine struct {
 	config   EngineConfig
 	updateFn func(PhaseUpdate)
 	promptFn func(InteractivePrompt)
+
+	// Last reported phase, reused for warnings so they don't move progress
+	lastPhase    AnalysisPhase
+	lastProgress float64
 }
 
 // NewEngine creates a new engine that delegates to uvx
@@ -102,6 +114,18 @@ func (e *Engine) SetPromptHandler(fn func(InteractivePrompt)) {
 // Run executes the analysis by spawning uvx skene-growth analyze
 func (e *Engine) Run(ctx context.Context) *AnalysisResult {
 	result := &AnalysisResult{}
+	defer e.notifyCompletion("analyze", time.Now(), result,
+		constants.GrowthManifestFile, constants.GrowthTemplateFile, constants.GrowthPlanFile, constants.GrowthHistoryFile)
+
+	if err := e.validateInputs(false); err != nil {
+		result.Error = fmt.Errorf("analysis failed: %w", err)
+		return result
+	}
+	if err := e.ensureOutputDirWritable(); err != nil {
+		result.Error = fmt.Errorf("analysis failed: %w", err)
+		return result
+	}
+	e.applyDefaultModel()
 
 	e.sendUpdate(PhaseScanCodebase, 0.0, "Starting analysis via uvx skene-growth...")
 
@@ -120,12 +144,29 @@ func (e *Engine) Run(ctx context.Context) *AnalysisResult {
 	result.Manifest = loadFileContent(filepath.Join(outputDir, constants.GrowthManifestFile))
 	result.GrowthTemplate = loadFileContent(filepath.Join(outputDir, constants.GrowthTemplateFile))
 
+	if e.config.TrackHistory {
+		if err := e.appendHistory(result.Manifest); err != nil {
+			e.sendWarning(fmt.Sprintf("could not update %s: %v", constants.GrowthHistoryFile, err))
+		}
+	}
+
 	return result
 }
 
 // GeneratePlan spawns uvx skene-growth plan
 func (e *Engine) GeneratePlan() *AnalysisResult {
 	result := &AnalysisResult{}
+	defer e.notifyCompletion("plan", time.Now(), result, constants.GrowthPlanFile)
+
+	if err := e.validateInputs(true); err != nil {
+		result.Error = fmt.Errorf("plan generation failed: %w", err)
+		return result
+	}
+	if err := e.ensureOutputDirWritable(); err != nil {
+		result.Error = fmt.Errorf("plan generation failed: %w", err)
+		return result
+	}
+	e.applyDefaultModel()
 
 	args := []string{constants.GrowthPackageName, "plan"}
 	args = append(args, e.buildCommonFlags()...)
@@ -143,6 +184,17 @@ func (e *Engine) GeneratePlan() *AnalysisResult {
 // GenerateBuild spawns uvx skene-growth build
 func (e *Engine) GenerateBuild() *AnalysisResult {
 	result := &AnalysisResult{}
+	defer e.notifyCompletion("build", time.Now(), result, constants.ImplementationPromptFile)
+
+	if err := e.validateInputs(true); err != nil {
+		result.Error = fmt.Errorf("build generation failed: %w", err)
+		return result
+	}
+	if err := e.ensureOutputDirWritable(); err != nil {
+		result.Error = fmt.Errorf("build generation failed: %w", err)
+		return result
+	}
+	e.applyDefaultModel()
 
 	args := []string{constants.GrowthPackageName, "build"}
 	args = append(args, e.buildCommonFlags()...)
@@ -160,6 +212,12 @@ func (e *Engine) GenerateBuild() *AnalysisResult {
 // ValidateManifest spawns uvx skene-growth validate
 func (e *Engine) ValidateManifest() *AnalysisResult {
 	result := &AnalysisResult{}
+	defer e.notifyCompletion("validate", time.Now(), result)
+
+	if err := e.validateInputs(true); err != nil {
+		result.Error = fmt.Errorf("validation failed: %w", err)
+		return result
+	}
 
 	manifestPath := filepath.Join(e.resolveOutputDir(), constants.GrowthManifestFile)
 	args := []string{constants.GrowthPackageName, "validate", manifestPath}
@@ -392,6 +450,21 @@ func isSelectLine(line string) bool {
 		strings.Contains(lower, "enter your choice")
 }
 
+// applyDefaultModel fills in the provider's default model when none is
+// configured, so uvx isn't started with a provider but no model. An explicit
+// model is always kept as-is.
+func (e *Engine) applyDefaultModel() {
+	if strings.TrimSpace(e.config.Model) != "" {
+		return
+	}
+	model := config.DefaultModelForProvider(e.config.Provider)
+	if model == "" {
+		return
+	}
+	e.config.Model = model
+	e.sendUpdate(PhaseScanCodebase, 0.0, fmt.Sprintf("No model configured, using %s default: %s", e.config.Provider, model))
+}
+
 func (e *Engine) buildCommonFlags() []string {
 	var flags []string
 	if e.config.Provider != "" {
@@ -436,7 +509,83 @@ func (e *Engine) resolveOutputDir() string {
 	return filepath.Join(e.config.ProjectDir, constants.OutputDirName)
 }
 
+// sendWarning emits a message under the phase that is currently active, so a
+// warning never marks a phase as done on a run that failed.
+func (e *Engine) sendWarning(message string) {
+	e.sendUpdate(e.lastPhase, e.lastProgress, "Warning: "+message)
+}
+
+// validateInputs checks the project directory and, for commands that build on
+// a previous analysis, the growth manifest. Paths are checked up front so a
+// bad config fails with a clear message instead of a uvx error.
+func (e *Engine) validateInputs(requireManifest bool) error {
+	if err := e.validateProjectDir(); err != nil {
+		return err
+	}
+	if !requireManifest {
+		return nil
+	}
+
+	manifestPath := filepath.Join(e.resolveOutputDir(), constants.GrowthManifestFile)
+	info, err := os.Stat(manifestPath)
+	if err != nil {
+		return fmt.Errorf("growth manifest not found: %s (run the analysis first)", manifestPath)
+	}
+	if info.IsDir() {
+		return fmt.Errorf("growth manifest path is a directory: %s", manifestPath)
+	}
+	return nil
+}
+
+// validateProjectDir checks that the project directory exists and is a directory
+func (e *Engine) validateProjectDir() error {
+	info, err := os.Stat(e.config.ProjectDir)
+	if err != nil {
+		return fmt.Errorf("project directory not found: %s", e.config.ProjectDir)
+	}
+	if !info.IsDir() {
+		return fmt.Errorf("project path is not a directory: %s", e.config.ProjectDir)
+	}
+	return nil
+}
+
+// ensureOutputDirWritable checks that uvx will be able to write the output
+// directory, so an unwritable location fails before any LLM calls are made.
+// Nothing is created: the probe file goes into the output directory if it
+// exists, otherwise into its nearest existing ancestor, so a failed or
+// cancelled run never leaves an empty skene-context/ that would later be
+// mistaken for a previous analysis.
+func (e *Engine) ensureOutputDirWritable() error {
+	outputDir := e.resolveOutputDir()
+
+	dir := outputDir
+	for {
+		info, err := os.Stat(dir)
+		if err == nil {
+			if !info.IsDir() {
+				return fmt.Errorf("output directory is not writable: %s is not a directory", dir)
+			}
+			break
+		}
+		parent := filepath.Dir(dir)
+		if parent == dir {
+			return fmt.Errorf("output directory is not writable: %s: %w", outputDir, err)
+		}
+		dir = parent
+	}
+
+	probe, err := os.CreateTemp(dir, ".skene-write-probe-*")
+	if err != nil {
+		return fmt.Errorf("output directory is not writable: %s: %w", outputDir, err)
+	}
+	probe.Close()
+	os.Remove(probe.Name())
+	return nil
+}
+
 func (e *Engine) sendUpdate(phase AnalysisPhase, progress float64, message string) {
+	e.lastPhase = phase
+	e.lastProgress = progress
 	if e.updateFn != nil {
 		e.updateFn(PhaseUpdate{
 			Phase:    phase,
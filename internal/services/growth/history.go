@@ -0,0 +1,188 @@
+package growth
+
+import (
+	"bufio"
+	"encoding/json"
+	"fmt"
+	"os"
+	"path/filepath"
+	"sort"
+	"strconv"
+	"strings"
+	"time"
+
+	"skene/internal/constants"
+)
+
+// trendTimeFormat is how run timestamps are shown in growth-trend.md
+const trendTimeFormat = "2006-01-02 15:04"
+
+// historyRecord is one line of growth-history.jsonl
+type historyRecord struct {
+	Timestamp time.Time      `json:"timestamp"`
+	Counts    map[string]int `json:"counts"`
+}
+
+// appendHistory records the manifest's list counts for this analysis in
+// growth-history.jsonl, creating the file if needed. Each record goes out in a
+// single O_APPEND write so concurrent runs can't interleave partial lines.
+func (e *Engine) appendHistory(manifest string) error {
+	counts := manifestCounts(manifest)
+	if counts == nil {
+		counts = map[string]int{}
+	}
+
+	line, err := json.Marshal(historyRecord{Timestamp: time.Now().UTC(), Counts: counts})
+	if err != nil {
+		return err
+	}
+
+	path := filepath.Join(e.resolveOutputDir(), constants.GrowthHistoryFile)
+	f, err := os.OpenFile(path, os.O_APPEND|os.O_CREATE|os.O_WRONLY, 0644)
+	if err != nil {
+		return err
+	}
+	if _, err := f.Write(append(line, '\n')); err != nil {
+		f.Close()
+		return err
+	}
+	return f.Close()
+}
+
+// GenerateTrend reads growth-history.jsonl and writes growth-trend.md with the
+// counts from each recorded run and how they changed. No LLM calls are made.
+func (e *Engine) GenerateTrend() *AnalysisResult {
+	result := &AnalysisResult{}
+	defer e.notifyCompletion("trend", time.Now(), result, constants.GrowthTrendFile)
+
+	if err := e.validateInputs(false); err != nil {
+		result.Error = fmt.Errorf("trend failed: %w", err)
+		return result
+	}
+
+	outputDir := e.resolveOutputDir()
+	records, err := loadHistory(filepath.Join(outputDir, constants.GrowthHistoryFile))
+	if err != nil {
+		result.Error = fmt.Errorf("trend failed: %w", err)
+		return result
+	}
+	if len(records) == 0 {
+		result.Error = fmt.Errorf("trend failed: no growth history yet (set track_history in %s and run the analysis)", constants.ProjectConfigFile)
+		return result
+	}
+
+	trendPath := filepath.Join(outputDir, constants.GrowthTrendFile)
+	if err := os.WriteFile(trendPath, []byte(renderTrend(records)), 0644); err != nil {
+		result.Error = fmt.Errorf("trend failed: %w", err)
+		return result
+	}
+
+	e.sendUpdate(PhaseGenerateDocs, 1.0, "Wrote "+trendPath)
+	return result
+}
+
+// loadHistory reads the records in a history file. A missing file is an empty
+// history, and lines that don't parse (e.g. from an interrupted write) are
+// skipped.
+func loadHistory(path string) ([]historyRecord, error) {
+	f, err := os.Open(path)
+	if os.IsNotExist(err) {
+		return nil, nil
+	}
+	if err != nil {
+		return nil, err
+	}
+	defer f.Close()
+
+	var records []historyRecord
+	scanner := bufio.NewScanner(f)
+	for scanner.Scan() {
+		var record historyRecord
+		if err := json.Unmarshal(scanner.Bytes(), &record); err != nil {
+			continue
+		}
+		records = append(records, record)
+	}
+	return records, scanner.Err()
+}
+
+// renderTrend renders the records as a markdown table with one row per run,
+// followed by the change in each count between the first and latest run
+func renderTrend(records []historyRecord) string {
+	keySet := make(map[string]bool)
+	for _, record := range records {
+		for key := range record.Counts {
+			keySet[key] = true
+		}
+	}
+	keys := make([]string, 0, len(keySet))
+	for key := range keySet {
+		keys = append(keys, key)
+	}
+	sort.Strings(keys)
+
+	first, last := records[0], records[len(records)-1]
+
+	var b strings.Builder
+	b.WriteString("# Growth Trend\n\n")
+	if len(records) == 1 {
+		fmt.Fprintf(&b, "1 run on %s (UTC).\n\n", first.Timestamp.UTC().Format(trendTimeFormat))
+	} else {
+		fmt.Fprintf(&b, "%d runs from %s to %s (UTC).\n\n", len(records),
+			first.Timestamp.UTC().Format(trendTimeFormat), last.Timestamp.UTC().Format(trendTimeFormat))
+	}
+
+	b.WriteString("| Run |")
+	for _, key := range keys {
+		b.WriteString(" " + fieldLabel(key) + " |")
+	}
+	b.WriteString("\n|-----|")
+	for range keys {
+		b.WriteString("---|")
+	}
+	b.WriteString("\n")
+
+	for i, record := range records {
+		b.WriteString("| " + record.Timestamp.UTC().Format(trendTimeFormat) + " |")
+		for _, key := range keys {
+			cell := "-"
+			if n, ok := record.Counts[key]; ok {
+				cell = strconv.Itoa(n)
+				if i > 0 {
+					if prev, had := records[i-1].Counts[key]; had && n != prev {
+						cell += " (" + formatDelta(n-prev) + ")"
+					}
+				}
+			}
+			b.WriteString(" " + cell + " |")
+		}
+		b.WriteString("\n")
+	}
+
+	if len(records) == 1 {
+		return b.String()
+	}
+
+	b.WriteString("\n## Since First Run\n\n")
+	for _, key := range keys {
+		from, okFrom := first.Counts[key]
+		to, okTo := last.Counts[key]
+		if !okFrom || !okTo {
+			continue
+		}
+		if from == to {
+			fmt.Fprintf(&b, "- **%s:** %d (no change)\n", fieldLabel(key), to)
+		} else {
+			fmt.Fprintf(&b, "- **%s:** %d → %d (%s)\n", fieldLabel(key), from, to, formatDelta(to-from))
+		}
+	}
+	return b.String()
+}
+
+// formatDelta renders a change in count with an explicit sign
+func formatDelta(delta int) string {
+	if delta > 0 {
+		return "+" + strconv.Itoa(delta)
+	}
+	return strconv.Itoa(delta)
+}
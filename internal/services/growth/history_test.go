@@ -0,0 +1,73 @@
+package growth
+
+import (
+	"testing"
+	"time"
+)
+
+func TestFormatDelta(t *testing.T) {
+	tests := []struct {
+		name string
+		in   int
+		want string
+	}{
+		{name: "increase", in: 3, want: "+3"},
+		{name: "decrease", in: -2, want: "-2"},
+		{name: "zero", in: 0, want: "0"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := formatDelta(tt.in); got != tt.want {
+				t.Errorf("formatDelta(%d) = %q, want %q", tt.in, got, tt.want)
+			}
+		})
+	}
+}
+
+func TestRenderTrend(t *testing.T) {
+	first := time.Date(2026, 10, 1, 9, 0, 0, 0, time.UTC)
+	second := time.Date(2026, 10, 8, 9, 30, 0, 0, time.UTC)
+
+	tests := []struct {
+		name    string
+		records []historyRecord
+		want    string
+	}{
+		{
+			name: "single run",
+			records: []historyRecord{
+				{Timestamp: first, Counts: map[string]int{"growth_opportunities": 6}},
+			},
+			want: "# Growth Trend\n\n" +
+				"1 run on 2026-10-01 09:00 (UTC).\n\n" +
+				"| Run | growth opportunities |\n" +
+				"|-----|---|\n" +
+				"| 2026-10-01 09:00 | 6 |\n",
+		},
+		{
+			name: "changes between runs",
+			records: []historyRecord{
+				{Timestamp: first, Counts: map[string]int{"current_growth_features": 4, "growth_opportunities": 6}},
+				{Timestamp: second, Counts: map[string]int{"current_growth_features": 5, "growth_opportunities": 6, "revenue_leakage": 2}},
+			},
+			want: "# Growth Trend\n\n" +
+				"2 runs from 2026-10-01 09:00 to 2026-10-08 09:30 (UTC).\n\n" +
+				"| Run | current growth features | growth opportunities | revenue leakage |\n" +
+				"|-----|---|---|---|\n" +
+				"| 2026-10-01 09:00 | 4 | 6 | - |\n" +
+				"| 2026-10-08 09:30 | 5 (+1) | 6 | 2 |\n" +
+				"\n## Since First Run\n\n" +
+				"- **current growth features:** 4 → 5 (+1)\n" +
+				"- **growth opportunities:** 6 (no change)\n",
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := renderTrend(tt.records); got != tt.want {
+				t.Errorf("renderTrend() = %q, want %q", got, tt.want)
+			}
+		})
+	}
+}
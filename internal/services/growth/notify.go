@@ -0,0 +1,152 @@
+package growth
+
+import (
+	"bytes"
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"os"
+	"path/filepath"
+	"sort"
+	"strings"
+	"time"
+
+	"skene/internal/constants"
+)
+
+// notifyTimeout bounds the webhook POST so an unreachable endpoint never
+// holds up the command that just finished
+const notifyTimeout = 5 * time.Second
+
+// CompletionNotice is the JSON payload posted to the notify webhook when a
+// command finishes. The field names are part of the documented payload and
+// should only ever be added to, never renamed.
+//
+// Text and Content carry the same one-line summary so the payload can be
+// posted straight to Slack (text) and Discord (content) incoming webhooks.
+type CompletionNotice struct {
+	Text      string         `json:"text"`
+	Content   string         `json:"content"`
+	Command   string         `json:"command"`
+	Status    string         `json:"status"`
+	OutputDir string         `json:"output_dir"`
+	Outputs   []string       `json:"outputs"`
+	Counts    map[string]int `json:"counts,omitempty"`
+	Error     string         `json:"error,omitempty"`
+}
+
+// notifyCompletion posts a CompletionNotice for the finished command. Only
+// files written since started are listed as outputs. Failures are reported as
+// a warning line only; they never change the command result.
+func (e *Engine) notifyCompletion(command string, started time.Time, result *AnalysisResult, files ...string) {
+	if e.config.NotifyWebhook == "" {
+		return
+	}
+
+	outputDir := e.resolveOutputDir()
+	notice := CompletionNotice{
+		Command:   command,
+		Status:    "success",
+		OutputDir: outputDir,
+		Outputs:   []string{},
+	}
+
+	// Truncate to the second so filesystems with coarse mtimes still match
+	since := started.Truncate(time.Second)
+	for _, name := range files {
+		path := filepath.Join(outputDir, name)
+		info, err := os.Stat(path)
+		if err == nil && !info.ModTime().Before(since) {
+			notice.Outputs = append(notice.Outputs, path)
+		}
+	}
+
+	if result.Error != nil {
+		notice.Status = "error"
+		notice.Error = result.Error.Error()
+		notice.Text = fmt.Sprintf("skene %s failed: %s", command, summarizeError(notice.Error))
+	} else {
+		notice.Counts = manifestCounts(loadFileContent(filepath.Join(outputDir, constants.GrowthManifestFile)))
+		notice.Text = fmt.Sprintf("skene %s finished in %s", command, outputDir)
+		if summary := formatCounts(notice.Counts); summary != "" {
+			notice.Text += " (" + summary + ")"
+		}
+	}
+	notice.Content = notice.Text
+
+	body, err := json.Marshal(notice)
+	if err != nil {
+		e.sendWarning(fmt.Sprintf("could not encode webhook payload: %v", err))
+		return
+	}
+
+	client := &http.Client{Timeout: notifyTimeout}
+	resp, err := client.Post(e.config.NotifyWebhook, "application/json", bytes.NewReader(body))
+	if err != nil {
+		e.sendWarning(fmt.Sprintf("completion webhook failed: %v", err))
+		return
+	}
+	resp.Body.Close()
+
+	if resp.StatusCode < 200 || resp.StatusCode >= 300 {
+		e.sendWarning(fmt.Sprintf("completion webhook returned %s", resp.Status))
+	}
+}
+
+// manifestFields decodes the manifest's top-level fields, or returns nil if it
+// isn't a JSON object. Callers only rely on the top-level shape, so they keep
+// working as the manifest schema evolves.
+func manifestFields(manifest string) map[string]interface{} {
+	var fields map[string]interface{}
+	if err := json.Unmarshal([]byte(manifest), &fields); err != nil {
+		return nil
+	}
+	return fields
+}
+
+// manifestCounts returns the number of entries in each top-level list of the
+// manifest, or nil if there are none or the manifest can't be parsed
+func manifestCounts(manifest string) map[string]int {
+	counts := make(map[string]int)
+	for key, value := range manifestFields(manifest) {
+		if list, ok := value.([]interface{}); ok {
+			counts[key] = len(list)
+		}
+	}
+	if len(counts) == 0 {
+		return nil
+	}
+	return counts
+}
+
+// fieldLabel turns a manifest key such as current_growth_features into a
+// human-readable label
+func fieldLabel(key string) string {
+	return strings.ReplaceAll(key, "_", " ")
+}
+
+// formatCounts renders counts as "key: n, key: n" in key order
+func formatCounts(counts map[string]int) string {
+	keys := make([]string, 0, len(counts))
+	for key := range counts {
+		keys = append(keys, key)
+	}
+	sort.Strings(keys)
+
+	parts := make([]string, 0, len(keys))
+	for _, key := range keys {
+		parts = append(parts, fmt.Sprintf("%s: %d", fieldLabel(key), counts[key]))
+	}
+	return strings.Join(parts, ", ")
+}
+
+// summarizeError collapses a multi-line error (uvx output tail) onto one line
+// and caps its length so chat messages stay readable
+func summarizeError(s string) string {
+	line := strings.Join(strings.Fields(s), " ")
+	// Cut on a rune boundary so multi-byte output isn't split mid-character
+	if runes := []rune(line); len(runes) > 300 {
+		line = string(runes[:297]) + "..."
+	}
+	return line
+}
@@ -0,0 +1,206 @@
+package growth
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"sort"
+	"strings"
+	"time"
+
+	"skene/internal/constants"
+)
+
+// reportSection is one markdown artifact bundled into the growth report
+type reportSection struct {
+	Title   string
+	File    string
+	Missing string
+}
+
+// jsonFence wraps embedded JSON; it is longer than the usual ``` so a
+// triple-backtick inside the JSON can't end the block early
+const jsonFence = "````"
+
+// reportSections lists the markdown artifacts in the order they appear in the
+// report. The manifest is handled separately since it is JSON.
+var reportSections = []reportSection{
+	{
+		Title:   "Growth Plan",
+		File:    constants.GrowthPlanFile,
+		Missing: "No growth plan yet. Select 'Generate Growth Plan' in Next Steps to create one.",
+	},
+	{
+		Title:   "Product Docs",
+		File:    constants.ProductDocsFile,
+		Missing: "No product docs were generated for this analysis.",
+	},
+	{
+		Title:   "Implementation Prompt",
+		File:    constants.ImplementationPromptFile,
+		Missing: "No implementation prompt yet. Select 'Build Implementation Prompt' in Next Steps to create one.",
+	},
+}
+
+// GenerateReport bundles the manifest, growth template and any existing
+// markdown artifacts in the output directory into a single growth-report.md.
+// No LLM calls are made.
+func (e *Engine) GenerateReport() *AnalysisResult {
+	result := &AnalysisResult{}
+	defer e.notifyCompletion("report", time.Now(), result, constants.GrowthReportFile)
+
+	if err := e.validateInputs(true); err != nil {
+		result.Error = fmt.Errorf("report generation failed: %w", err)
+		return result
+	}
+
+	outputDir := e.resolveOutputDir()
+	manifest := loadFileContent(filepath.Join(outputDir, constants.GrowthManifestFile))
+	template := loadFileContent(filepath.Join(outputDir, constants.GrowthTemplateFile))
+
+	var b strings.Builder
+	b.WriteString("# Growth Report\n\n")
+	b.WriteString(fmt.Sprintf("Generated %s from `%s`.\n\n", time.Now().Format("2006-01-02 15:04"), outputDir))
+
+	b.WriteString("## Contents\n\n")
+	b.WriteString("- [Executive Summary](#executive-summary)\n")
+	for _, section := range reportSections {
+		b.WriteString(fmt.Sprintf("- [%s](#%s)\n", section.Title, markdownAnchor(section.Title)))
+	}
+	b.WriteString("- [Growth Template](#growth-template)\n")
+	b.WriteString("- [Growth Manifest](#growth-manifest)\n\n")
+
+	b.WriteString("## Executive Summary\n\n")
+	b.WriteString(summarizeManifest(manifest))
+	b.WriteString("\n")
+
+	for _, section := range reportSections {
+		e.sendUpdate(PhaseGenerateDocs, 0.5, "Adding "+section.Title)
+		b.WriteString(fmt.Sprintf("## %s\n\n", section.Title))
+		content := strings.TrimSpace(loadFileContent(filepath.Join(outputDir, section.File)))
+		if content == "" {
+			b.WriteString("_" + section.Missing + "_\n\n")
+			continue
+		}
+		b.WriteString(demoteHeadings(content, 2))
+		b.WriteString("\n\n")
+	}
+
+	b.WriteString("## Growth Template\n\n")
+	if strings.TrimSpace(template) == "" {
+		b.WriteString("_No growth template was generated for this analysis._\n\n")
+	} else {
+		b.WriteString(jsonFence + "json\n")
+		b.WriteString(strings.TrimSpace(template))
+		b.WriteString("\n" + jsonFence + "\n\n")
+	}
+
+	b.WriteString("## Growth Manifest\n\n")
+	b.WriteString(jsonFence + "json\n")
+	b.WriteString(strings.TrimSpace(manifest))
+	b.WriteString("\n" + jsonFence + "\n")
+
+	reportPath := filepath.Join(outputDir, constants.GrowthReportFile)
+	if err := os.WriteFile(reportPath, []byte(b.String()), 0644); err != nil {
+		result.Error = fmt.Errorf("report generation failed: %w", err)
+		return result
+	}
+
+	e.sendUpdate(PhaseGenerateDocs, 1.0, "Wrote "+reportPath)
+	result.Manifest = manifest
+	return result
+}
+
+// summarizeManifest lists the manifest's top-level fields for the report:
+// lists are shown as entry counts and short text values as-is
+func summarizeManifest(manifest string) string {
+	fields := manifestFields(manifest)
+	if fields == nil {
+		return "_The growth manifest could not be parsed; see the raw manifest below._\n"
+	}
+	counts := manifestCounts(manifest)
+
+	keys := make([]string, 0, len(fields))
+	for key := range fields {
+		keys = append(keys, key)
+	}
+	sort.Strings(keys)
+
+	var lines []string
+	for _, key := range keys {
+		if n, ok := counts[key]; ok {
+			lines = append(lines, fmt.Sprintf("- **%s:** %d entries", fieldLabel(key), n))
+			continue
+		}
+		value, ok := fields[key].(string)
+		if ok && value != "" && len(value) <= 120 && !strings.Contains(value, "\n") {
+			lines = append(lines, fmt.Sprintf("- **%s:** %s", fieldLabel(key), value))
+		}
+	}
+
+	if len(lines) == 0 {
+		return "_The growth manifest has no summary fields._\n"
+	}
+	return strings.Join(lines, "\n") + "\n"
+}
+
+// demoteHeadings pushes every markdown heading down by levels (capped at six)
+// so bundled documents nest under the report's own section headings. Lines
+// inside fenced code blocks are left untouched, and a fence left open at the
+// end is closed so it can't swallow the report sections that follow.
+func demoteHeadings(markdown string, levels int) string {
+	lines := strings.Split(markdown, "\n")
+	openFence := ""
+	for i, line := range lines {
+		if marker := fenceMarker(line); marker != "" {
+			if openFence == "" {
+				openFence = marker
+				continue
+			}
+			// Only a bare run of the opening character, at least as long as
+			// the opener, closes the block
+			rest := strings.TrimSpace(strings.TrimLeft(line, " ")[len(marker):])
+			if marker[0] == openFence[0] && len(marker) >= len(openFence) && rest == "" {
+				openFence = ""
+			}
+			continue
+		}
+		if openFence != "" || !strings.HasPrefix(line, "#") {
+			continue
+		}
+
+		depth := len(line) - len(strings.TrimLeft(line, "#"))
+		if depth > 6 || (len(line) > depth && line[depth] != ' ') {
+			// Not a heading, e.g. "#hashtag" or a run of hashes
+			continue
+		}
+		newDepth := depth + levels
+		if newDepth > 6 {
+			newDepth = 6
+		}
+		lines[i] = strings.Repeat("#", newDepth) + line[depth:]
+	}
+
+	if openFence != "" {
+		lines = append(lines, openFence)
+	}
+	return strings.Join(lines, "\n")
+}
+
+// fenceMarker returns the run of backticks or tildes that starts a fenced
+// code block line, or "" if the line isn't a fence
+func fenceMarker(line string) string {
+	trimmed := strings.TrimLeft(line, " ")
+	for _, c := range []string{"`", "~"} {
+		run := len(trimmed) - len(strings.TrimLeft(trimmed, c))
+		if run >= 3 {
+			return trimmed[:run]
+		}
+	}
+	return ""
+}
+
+// markdownAnchor converts a heading to the anchor GitHub generates for it
+func markdownAnchor(title string) string {
+	return strings.ReplaceAll(strings.ToLower(title), " ", "-")
+}
@@ -0,0 +1,86 @@
+package growth
+
+import "testing"
+
+func TestDemoteHeadings(t *testing.T) {
+	tests := []struct {
+		name string
+		in   string
+		want string
+	}{
+		{name: "top-level heading", in: "# Title\n\nBody", want: "### Title\n\nBody"},
+		{name: "hashtag is not a heading", in: "#hashtag and text", want: "#hashtag and text"},
+		{name: "too many hashes is not a heading", in: "####### seven", want: "####### seven"},
+		{name: "depth capped at six", in: "##### Deep\n###### Deepest", want: "###### Deep\n###### Deepest"},
+		{name: "backtick fence", in: "```\n# inside\n```\n# after", want: "```\n# inside\n```\n### after"},
+		{name: "tilde fence", in: "~~~md\n# inside\n~~~\n# after", want: "~~~md\n# inside\n~~~\n### after"},
+		{name: "backticks inside tilde fence", in: "~~~\n```\n# inside\n~~~\n# after", want: "~~~\n```\n# inside\n~~~\n### after"},
+		{name: "shorter marker does not close", in: "````md\n```\n# inside\n````\n# after", want: "````md\n```\n# inside\n````\n### after"},
+		{name: "marker with info string does not close", in: "```\n```go\n# inside\n```\n# after", want: "```\n```go\n# inside\n```\n### after"},
+		{name: "unclosed fence is closed", in: "```go\n# inside", want: "```go\n# inside\n```"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := demoteHeadings(tt.in, 2); got != tt.want {
+				t.Errorf("demoteHeadings(%q, 2) = %q, want %q", tt.in, got, tt.want)
+			}
+		})
+	}
+}
+
+func TestMarkdownAnchor(t *testing.T) {
+	tests := []struct {
+		name string
+		in   string
+		want string
+	}{
+		{name: "single word", in: "Contents", want: "contents"},
+		{name: "multiple words", in: "Implementation Prompt", want: "implementation-prompt"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := markdownAnchor(tt.in); got != tt.want {
+				t.Errorf("markdownAnchor(%q) = %q, want %q", tt.in, got, tt.want)
+			}
+		})
+	}
+}
+
+func TestSummarizeManifest(t *testing.T) {
+	tests := []struct {
+		name string
+		in   string
+		want string
+	}{
+		{
+			name: "invalid json",
+			in:   "not json",
+			want: "_The growth manifest could not be parsed; see the raw manifest below._\n",
+		},
+		{
+			name: "lists and short strings",
+			in:   `{"project_name":"Acme","current_growth_features":[1,2],"summary":"multi\nline","tech_stack":{"language":"Go"}}`,
+			want: "- **current growth features:** 2 entries\n- **project name:** Acme\n",
+		},
+		{
+			name: "empty list",
+			in:   `{"growth_opportunities":[]}`,
+			want: "- **growth opportunities:** 0 entries\n",
+		},
+		{
+			name: "no summary fields",
+			in:   `{"tech_stack":{"language":"Go"}}`,
+			want: "_The growth manifest has no summary fields._\n",
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := summarizeManifest(tt.in); got != tt.want {
+				t.Errorf("summarizeManifest(%q) = %q, want %q", tt.in, got, tt.want)
+			}
+		})
+	}
+}
@@ -852,6 +852,10 @@ func (a *App) handleNextStepsKeys(key string) tea.Cmd {
 			return a.runEngineCommand("Building Implementation Prompt", "build")
 		case "validate":
 			return a.runEngineCommand("Validating Manifest", "validate")
+		case "report":
+			return a.runEngineCommand("Building Growth Report", "report")
+		case "trend":
+			return a.runEngineCommand("Building Growth Trend", "trend")
 		case "open":
 			projectDir := a.configMgr.Config.ProjectDir
 			if projectDir == "" {
@@ -1241,6 +1245,16 @@ func (a *App) runEngineCommand(title string, command string) tea.Cmd {
 				p.Send(NextStepOutputMsg{Line: "Running: uvx skene-growth validate ..."})
 			}
 			result = engine.ValidateManifest()
+		case "report":
+			if p != nil {
+				p.Send(NextStepOutputMsg{Line: "Assembling " + constants.GrowthReportFile + " ..."})
+			}
+			result = engine.GenerateReport()
+		case "trend":
+			if p != nil {
+				p.Send(NextStepOutputMsg{Line: "Reading " + constants.GrowthHistoryFile + " ..."})
+			}
+			result = engine.GenerateTrend()
 		default:
 			return NextStepDoneMsg{Error: fmt.Errorf("unknown command: %s", command)}
 		}
@@ -1315,6 +1329,12 @@ func analysisErrorSuggestion(err error) string {
 	if containsAny(s, "No module named", "not found: skene-growth", "package not found") {
 		return "The skene-growth package could not be found. Make sure it is published or install it manually."
 	}
+	if containsAny(s, "project directory not found", "project path is not a directory") {
+		return "Select an existing project directory, or fix project_dir in .skene.config."
+	}
+	if containsAny(s, "output directory is not writable") {
+		return "Check the permissions on the output directory, or set output_dir in .skene.config to a writable location."
+	}
 	if containsAny(s, "API key", "401", "unauthorized") {
 		return "Check your API key, ensure it has the required permissions, and try again."
 	}
@@ -1553,13 +1573,15 @@ func (a *App) buildEngineConfig() growth.EngineConfig {
 	}
 
 	return growth.EngineConfig{
-		Provider:    a.configMgr.Config.Provider,
-		Model:       a.configMgr.Config.Model,
-		APIKey:      a.configMgr.Config.APIKey,
-		BaseURL:     a.configMgr.Config.BaseURL,
-		ProjectDir:  projectDir,
-		OutputDir:   outputDir,
-		UseGrowth: a.configMgr.Config.UseGrowth,
+		Provider:      a.configMgr.Config.Provider,
+		Model:         a.configMgr.Config.Model,
+		APIKey:        a.configMgr.Config.APIKey,
+		BaseURL:       config.NormalizeBaseURL(a.configMgr.Config.BaseURL),
+		ProjectDir:    projectDir,
+		OutputDir:     outputDir,
+		UseGrowth:     a.configMgr.Config.UseGrowth,
+		NotifyWebhook: a.configMgr.Config.NotifyWebhook,
+		TrackHistory:  a.configMgr.Config.TrackHistory,
 	}
 }
 